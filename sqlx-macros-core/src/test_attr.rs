@@ -5,23 +5,72 @@ use syn::{parse::Parser, Meta};
 #[cfg(feature = "migrate")]
 use proc_macro2::Span;
 #[cfg(feature = "migrate")]
+use std::path::Path;
+#[cfg(feature = "migrate")]
 use syn::{punctuated::Punctuated, Expr, ExprLit, Lit, LitStr, MetaNameValue, Token};
 
 #[cfg(feature = "migrate")]
 struct Args {
-    fixtures: Vec<LitStr>,
+    fixtures: Fixtures,
+    fixture_scheme: FixtureScheme,
     #[cfg(feature = "migrate")]
     migrations: MigrationsOpt,
 }
 
+/// How the `fixtures(...)` argument names the SQL files to apply.
+#[cfg(feature = "migrate")]
+enum Fixtures {
+    /// No `fixtures(...)` argument was given.
+    None,
+    /// `fixtures("accounts", "posts")` — each resolves to `fixtures/{name}.sql`.
+    Names(Vec<LitStr>),
+    /// `fixtures(path = "fixtures/accounts")` — every `.sql` file in a directory.
+    Path(LitStr),
+    /// `fixtures(glob = "fixtures/users/*.sql")` — files matching a glob.
+    Glob(LitStr),
+}
+
+/// How discovered fixtures are ordered before they are applied.
+///
+/// This controls *ordering only*: both variants emit the same
+/// `TestFixture { path, contents }` the runtime applies in slice order.
+///
+/// The versioned-fixtures request is therefore only partially delivered here:
+/// the `V{version}__` prefix is parsed, used to order fixtures, and checked for
+/// collisions, but the parsed version is *not* recorded on `TestFixture`.
+/// Doing so — so the runtime could apply fixtures relative to the migrator's
+/// last applied version — needs a new `TestFixture.version` field in the
+/// testing runtime crate, which is outside this change set and left as
+/// follow-up.
+#[cfg(feature = "migrate")]
+enum FixtureScheme {
+    /// Sort by raw filename (the default).
+    Lexicographic,
+    /// Sort by the numeric prefix of the `V{version}__{description}.sql`
+    /// convention, rejecting duplicate versions at compile time.
+    Versioned,
+}
+
 #[cfg(feature = "migrate")]
 enum MigrationsOpt {
     InferredPath,
-    ExplicitPath(LitStr),
-    ExplicitMigrator(syn::Path),
+    /// One or more explicit sources, merged in listed order. Produced by
+    /// `migrations = "..."`, `migrations = ["...", "..."]`, and `migrator = ...`,
+    /// which may be combined.
+    Explicit(Vec<MigratorSource>),
     Disabled,
 }
 
+/// A single source contributing migrations to the merged migrator.
+#[cfg(feature = "migrate")]
+enum MigratorSource {
+    /// A directory of `.sql` migrations, embedded via
+    /// [`crate::migrate::expand_migrator_from_lit_dir`].
+    Dir(LitStr),
+    /// A Rust path to an existing `Migrator` (`migrator = "..."`).
+    Migrator(syn::Path),
+}
+
 type AttributeArgs = syn::punctuated::Punctuated<Meta, syn::Token![,]>;
 
 pub fn expand(args: TokenStream, input: syn::ItemFn) -> crate::Result<TokenStream> {
@@ -85,22 +134,10 @@ fn expand_advanced(args: AttributeArgs, input: syn::ItemFn) -> crate::Result<Tok
 
     let fn_arg_types = inputs.iter().map(|_| quote! { _ });
 
-    let fixtures = args.fixtures.into_iter().map(|fixture| {
-        let path = format!("fixtures/{}.sql", fixture.value());
-
-        quote! {
-            ::sqlx::testing::TestFixture {
-                path: #path,
-                contents: include_str!(#path),
-            }
-        }
-    });
+    let fixtures = expand_fixtures(args.fixtures, args.fixture_scheme)?;
 
     let migrations = match args.migrations {
-        MigrationsOpt::ExplicitPath(path) => {
-            let migrator = crate::migrate::expand_migrator_from_lit_dir(path)?;
-            quote! { args.migrator(&#migrator); }
-        }
+        MigrationsOpt::Explicit(sources) => expand_migrator_sources(sources)?,
         MigrationsOpt::InferredPath if !inputs.is_empty() => {
             let migrations_path = crate::common::resolve_path("./migrations", Span::call_site())?;
 
@@ -111,9 +148,6 @@ fn expand_advanced(args: AttributeArgs, input: syn::ItemFn) -> crate::Result<Tok
                 quote! {}
             }
         }
-        MigrationsOpt::ExplicitMigrator(path) => {
-            quote! { args.migrator(&#path); }
-        }
         _ => quote! {},
     };
 
@@ -139,9 +173,417 @@ fn expand_advanced(args: AttributeArgs, input: syn::ItemFn) -> crate::Result<Tok
     })
 }
 
+/// Embed every [`MigratorSource`] and, when more than one is listed, merge
+/// their migration sets — in listed order — into a single owned migrator.
+/// Duplicate version numbers across sources are rejected before any code is
+/// emitted.
+#[cfg(feature = "migrate")]
+fn expand_migrator_sources(sources: Vec<MigratorSource>) -> crate::Result<TokenStream> {
+    check_duplicate_migration_versions(&sources)?;
+
+    let migrators = sources
+        .iter()
+        .map(|source| match source {
+            MigratorSource::Dir(dir) => crate::migrate::expand_migrator_from_lit_dir(dir.clone()),
+            MigratorSource::Migrator(path) => Ok(quote! { #path }),
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    // A single source keeps the original allocation-free `args.migrator(&M)`.
+    if let [migrator] = migrators.as_slice() {
+        return Ok(quote! { args.migrator(&#migrator); });
+    }
+
+    // The merged migrator owns a concatenated migration list, so it can't be
+    // promoted to `'static` like the single-source literal. Host it on the
+    // first source and reference the rest: that needs only the public
+    // `Migrator::migrations` field (and `Migration: Clone`), not a `Migrator:
+    // Clone` impl the runtime doesn't provide. The first source is moved, so it
+    // must be an owned directory literal rather than a `migrator = ...` path
+    // pointing at someone else's `static`.
+    if let MigratorSource::Migrator(path) = &sources[0] {
+        return Err(syn::Error::new_spanned(
+            path,
+            "a `migrator = ...` source cannot be first when merging multiple \
+             sources; list a `migrations = \"...\"` directory first",
+        )
+        .into());
+    }
+
+    let (first, rest) = migrators.split_first().expect("more than one source");
+    let rest_idents = (0..rest.len())
+        .map(|i| quote::format_ident!("__migrator_{}", i))
+        .collect::<Vec<_>>();
+    let rest_bindings = rest_idents
+        .iter()
+        .zip(rest)
+        .map(|(ident, migrator)| quote! { let #ident = &#migrator; });
+
+    Ok(quote! {
+        {
+            // Intern the merged migrator once per wrapper rather than leaking a
+            // fresh allocation on every call: `TestArgs::migrator` wants a
+            // `&'static Migrator`, and `OnceLock` hands one out for free.
+            static __COMBINED: ::std::sync::OnceLock<::sqlx::migrate::Migrator> =
+                ::std::sync::OnceLock::new();
+
+            let __combined = __COMBINED.get_or_init(|| {
+                #(#rest_bindings)*
+
+                let mut __migrator = #first;
+                let mut __migrations =
+                    __migrator.migrations.iter().cloned().collect::<::std::vec::Vec<_>>();
+                #( __migrations.extend(#rest_idents.migrations.iter().cloned()); )*
+                __migrator.migrations = ::std::borrow::Cow::Owned(__migrations);
+                __migrator
+            });
+
+            args.migrator(__combined);
+        }
+    })
+}
+
+/// Reject duplicate migration versions across directory sources, reporting the
+/// collision against the offending path literal. Rust `migrator = ...` sources
+/// are opaque and cannot be inspected here.
+#[cfg(feature = "migrate")]
+fn check_duplicate_migration_versions(sources: &[MigratorSource]) -> crate::Result<()> {
+    use std::collections::{HashMap, HashSet};
+
+    let mut seen: HashMap<i64, ()> = HashMap::new();
+
+    for source in sources {
+        let MigratorSource::Dir(dir) = source else {
+            continue;
+        };
+
+        let resolved = crate::common::resolve_path(dir.value(), dir.span())?;
+
+        if !resolved.is_dir() {
+            // Leave the missing-directory diagnostic to the embedding macro.
+            continue;
+        }
+
+        // A single migration can span `*.up.sql`/`*.down.sql`, so collapse to
+        // the distinct versions within this directory before cross-checking.
+        let mut local = HashSet::new();
+
+        for entry in std::fs::read_dir(&resolved)
+            .map_err(|e| syn::Error::new_spanned(dir, format!("error reading migrations: {e}")))?
+        {
+            let entry = entry.map_err(|e| {
+                syn::Error::new_spanned(dir, format!("error reading migrations: {e}"))
+            })?;
+            let path = entry.path();
+
+            if path.extension().and_then(|ext| ext.to_str()) != Some("sql") {
+                continue;
+            }
+
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+
+            if let Some(version) = migration_version(name) {
+                local.insert(version);
+            }
+        }
+
+        for version in local {
+            if seen.insert(version, ()).is_some() {
+                return Err(syn::Error::new_spanned(
+                    dir,
+                    format!("duplicate migration version {version} across sources"),
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse the leading integer version of a `{version}_{description}.sql`
+/// migration file name, as the migrator's own discovery does.
+#[cfg(feature = "migrate")]
+fn migration_version(name: &str) -> Option<i64> {
+    let digits: String = name.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// Turn a parsed [`Fixtures`] spec into one `TestFixture` initializer per
+/// discovered `.sql` file. Directory and glob forms are enumerated here, at
+/// expansion time, so migration-style embedding can reuse `resolve_path` and
+/// report missing directories with a spanned error.
+///
+/// Under [`FixtureScheme::Versioned`] the files are ordered by the numeric
+/// prefix of the `V{version}__{description}.sql` convention rather than by raw
+/// filename, and a duplicate version is rejected with a spanned compile error.
+/// The ordering is resolved entirely here, at expansion time; the emitted
+/// `TestFixture` is the same `{ path, contents }` shape the runtime already
+/// applies in slice order.
+#[cfg(feature = "migrate")]
+fn expand_fixtures(fixtures: Fixtures, scheme: FixtureScheme) -> crate::Result<Vec<TokenStream>> {
+    fn fixture_tokens(fixture: &DiscoveredFixture) -> TokenStream {
+        // The `path` field stays relative for stable display/keying, while
+        // discovered files give `include_str!` the absolute path `resolve_path`
+        // produced; bare names keep the relative `fixtures/{name}.sql`.
+        let path = &fixture.path;
+        let include = &fixture.include;
+
+        quote! {
+            ::sqlx::testing::TestFixture {
+                path: #path,
+                contents: include_str!(#include),
+            }
+        }
+    }
+
+    // Collect each fixture's repo-relative `path` and absolute `include_str!`
+    // path together with a span to blame if it violates the naming scheme.
+    let files: Vec<DiscoveredFixture> = match fixtures {
+        Fixtures::None => vec![],
+        Fixtures::Names(names) => names
+            .into_iter()
+            .map(|name| {
+                let path = format!("fixtures/{}.sql", name.value());
+                DiscoveredFixture {
+                    include: path.clone(),
+                    path,
+                    span: name.span(),
+                }
+            })
+            .collect(),
+        Fixtures::Path(dir) => discover_fixtures(&dir, None)?,
+        Fixtures::Glob(glob) => {
+            let value = glob.value();
+            let (dir, pattern) = match value.rsplit_once('/') {
+                Some((dir, pattern)) => (dir, pattern),
+                None => (".", value.as_str()),
+            };
+
+            let dir = LitStr::new(dir, glob.span());
+            discover_fixtures(&dir, Some(pattern))?
+        }
+    };
+
+    match scheme {
+        FixtureScheme::Lexicographic => Ok(files.iter().map(fixture_tokens).collect()),
+        FixtureScheme::Versioned => {
+            let mut versioned = files
+                .iter()
+                .map(|fixture| {
+                    let name = fixture.path.rsplit('/').next().unwrap_or(&fixture.path);
+
+                    parse_fixture_version(name)
+                        .map(|version| (version, fixture))
+                        .ok_or_else(|| {
+                            syn::Error::new(
+                                fixture.span,
+                                format!(
+                                    "fixture {name:?} does not follow the \
+                                     `V{{version}}__{{description}}.sql` scheme"
+                                ),
+                            )
+                            .into()
+                        })
+                })
+                .collect::<crate::Result<Vec<_>>>()?;
+
+            versioned.sort_by_key(|(version, ..)| *version);
+
+            for pair in versioned.windows(2) {
+                if pair[0].0 == pair[1].0 {
+                    return Err(syn::Error::new(
+                        pair[1].1.span,
+                        format!("duplicate fixture version {}", pair[1].0),
+                    )
+                    .into());
+                }
+            }
+
+            Ok(versioned
+                .into_iter()
+                .map(|(_, fixture)| fixture_tokens(fixture))
+                .collect())
+        }
+    }
+}
+
+/// A fixture file resolved at expansion time: the relative `path` recorded on
+/// `TestFixture`, the path fed to `include_str!` (absolute for directory and
+/// glob discovery), and the span to blame for scheme violations.
+#[cfg(feature = "migrate")]
+struct DiscoveredFixture {
+    path: String,
+    include: String,
+    span: Span,
+}
+
+#[cfg(feature = "migrate")]
+fn path_to_string(path: &Path) -> Option<String> {
+    path.to_str().map(ToOwned::to_owned)
+}
+
+/// Join the repo-relative directory a user wrote with a discovered file name,
+/// keeping the `path` field relative and platform-neutral (`/` separators).
+#[cfg(feature = "migrate")]
+fn relative_fixture_path(dir: &str, file_name: &str) -> String {
+    let dir = dir.trim_end_matches('/');
+
+    if dir.is_empty() || dir == "." {
+        file_name.to_owned()
+    } else {
+        format!("{dir}/{file_name}")
+    }
+}
+
+/// Parse the numeric version prefix of a `V{version}__{description}.sql` file
+/// name. Returns `None` if the name does not start with `V` or `v`, has no
+/// `__` separator, or the prefix is not an integer.
+#[cfg(feature = "migrate")]
+fn parse_fixture_version(name: &str) -> Option<i64> {
+    let rest = name.strip_prefix('V').or_else(|| name.strip_prefix('v'))?;
+    let (version, _) = rest.split_once("__")?;
+    version.parse().ok()
+}
+
+/// Enumerate `.sql` files under `dir` (resolved against `CARGO_MANIFEST_DIR`),
+/// optionally restricted to those whose file name matches `pattern`, returning
+/// them sorted lexicographically so application order is deterministic. The
+/// recorded `path` is `dir` as written joined with the file name, while
+/// `include` holds the absolute path `include_str!` needs.
+#[cfg(feature = "migrate")]
+fn discover_fixtures(dir: &LitStr, pattern: Option<&str>) -> crate::Result<Vec<DiscoveredFixture>> {
+    let resolved = crate::common::resolve_path(dir.value(), dir.span())?;
+
+    if !resolved.is_dir() {
+        return Err(syn::Error::new_spanned(
+            dir,
+            format!("fixture directory {:?} does not exist", dir.value()),
+        )
+        .into());
+    }
+
+    let dir_value = dir.value();
+    let mut files = vec![];
+
+    for entry in std::fs::read_dir(&resolved)
+        .map_err(|e| syn::Error::new_spanned(dir, format!("error reading fixtures: {e}")))?
+    {
+        let entry = entry
+            .map_err(|e| syn::Error::new_spanned(dir, format!("error reading fixtures: {e}")))?;
+        let path = entry.path();
+
+        if path.extension().and_then(|ext| ext.to_str()) != Some("sql") {
+            continue;
+        }
+
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        if let Some(pattern) = pattern {
+            if !glob_match(pattern, name) {
+                continue;
+            }
+        }
+
+        let include = path_to_string(&path).ok_or_else(|| {
+            syn::Error::new_spanned(
+                dir,
+                format!("fixture path {path:?} is not valid UTF-8"),
+            )
+        })?;
+
+        files.push(DiscoveredFixture {
+            path: relative_fixture_path(&dir_value, name),
+            include,
+            span: dir.span(),
+        });
+    }
+
+    if files.is_empty() {
+        return Err(syn::Error::new_spanned(
+            dir,
+            "no matching `.sql` fixtures were found",
+        )
+        .into());
+    }
+
+    // Order by the relative path rather than the absolute one so application
+    // order does not depend on the prefix `resolve_path` prepends.
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(files)
+}
+
+/// Match a single path component against a shell-style glob supporting `*`
+/// (any run of characters) and `?` (a single character).
+#[cfg(feature = "migrate")]
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Classic backtracking matcher with a remembered `*` position.
+    let (mut p, mut n) = (0, 0);
+    let (mut star, mut star_n) = (None, 0);
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some(p);
+            star_n = n;
+            p += 1;
+        } else if let Some(star_p) = star {
+            p = star_p + 1;
+            star_n += 1;
+            n = star_n;
+        } else {
+            return false;
+        }
+    }
+
+    while p < pattern.len() && pattern[p] == '*' {
+        p += 1;
+    }
+
+    p == pattern.len()
+}
+
+/// Accumulate migration sources into `migrations`, switching the default
+/// `InferredPath` to `Explicit` on the first source and rejecting any attempt
+/// to combine sources with `migrations = false`.
+#[cfg(feature = "migrate")]
+fn push_migrator_sources(
+    path: &syn::Path,
+    migrations: &mut MigrationsOpt,
+    sources: impl Iterator<Item = MigratorSource>,
+) -> Result<(), syn::Error> {
+    match migrations {
+        MigrationsOpt::Disabled => Err(syn::Error::new_spanned(
+            path,
+            "cannot combine `migrations = false` with other migration sources",
+        )),
+        MigrationsOpt::InferredPath => {
+            *migrations = MigrationsOpt::Explicit(sources.collect());
+            Ok(())
+        }
+        MigrationsOpt::Explicit(existing) => {
+            existing.extend(sources);
+            Ok(())
+        }
+    }
+}
+
 #[cfg(feature = "migrate")]
 fn parse_args(args: AttributeArgs) -> Result<Args, syn::Error> {
-    let mut fixtures = vec![];
+    let mut fixtures = Fixtures::None;
+    let mut fixture_scheme = FixtureScheme::Lexicographic;
     let mut migrations = MigrationsOpt::InferredPath;
 
     for arg in args {
@@ -149,55 +591,161 @@ fn parse_args(args: AttributeArgs) -> Result<Args, syn::Error> {
 
         match arg {
             Meta::List(list) if path.is_ident("fixtures") => {
-                if !fixtures.is_empty() {
+                if !matches!(fixtures, Fixtures::None) {
                     return Err(syn::Error::new_spanned(path, "duplicate `fixtures` arg"));
                 }
 
-                let parser = <Punctuated<LitStr, Token![,]>>::parse_terminated;
+                let parser = <Punctuated<Expr, Token![,]>>::parse_terminated;
                 let list = parser.parse2(list.tokens)?;
-                fixtures.extend(list);
-            }
-            Meta::NameValue(MetaNameValue { value, .. }) if path.is_ident("migrations") => {
-                if !matches!(migrations, MigrationsOpt::InferredPath) {
-                    return Err(syn::Error::new_spanned(
-                        path,
-                        "cannot have more than one `migrations` or `migrator` arg",
-                    ));
-                }
 
-                let Expr::Lit(ExprLit { lit, .. }) = value else {
-                    return Err(syn::Error::new_spanned(path, "expected string for `false`"))
-                };
+                let mut names = vec![];
+
+                for expr in list {
+                    match expr {
+                        // fixtures("<name>", ...)
+                        Expr::Lit(ExprLit { lit: Lit::Str(name), .. }) => {
+                            if !matches!(fixtures, Fixtures::None) {
+                                return Err(syn::Error::new_spanned(
+                                    name,
+                                    "cannot mix bare fixture names with `path`/`glob`",
+                                ));
+                            }
+
+                            names.push(name);
+                        }
+                        // fixtures(path = "...") | fixtures(glob = "...")
+                        Expr::Assign(assign) => {
+                            let Expr::Path(key) = &*assign.left else {
+                                return Err(syn::Error::new_spanned(
+                                    assign.left,
+                                    "expected `path`, `glob`, or `scheme`",
+                                ));
+                            };
+
+                            let Expr::Lit(ExprLit { lit: Lit::Str(value), .. }) = &*assign.right
+                            else {
+                                return Err(syn::Error::new_spanned(
+                                    assign.right,
+                                    "expected a string literal",
+                                ));
+                            };
+
+                            // `scheme` tweaks ordering and composes with any source.
+                            if key.path.is_ident("scheme") {
+                                fixture_scheme = match &*value.value() {
+                                    "lexicographic" => FixtureScheme::Lexicographic,
+                                    "versioned" => FixtureScheme::Versioned,
+                                    _ => {
+                                        return Err(syn::Error::new_spanned(
+                                            value,
+                                            r#"expected `"lexicographic"` or `"versioned"`"#,
+                                        ));
+                                    }
+                                };
+
+                                continue;
+                            }
+
+                            if !names.is_empty() || !matches!(fixtures, Fixtures::None) {
+                                return Err(syn::Error::new_spanned(
+                                    &key.path,
+                                    "`path`/`glob` cannot be combined with other fixture sources",
+                                ));
+                            }
+
+                            if key.path.is_ident("path") {
+                                fixtures = Fixtures::Path(value.clone());
+                            } else if key.path.is_ident("glob") {
+                                fixtures = Fixtures::Glob(value.clone());
+                            } else {
+                                return Err(syn::Error::new_spanned(
+                                    &key.path,
+                                    "expected `path`, `glob`, or `scheme`",
+                                ));
+                            }
+                        }
+                        other => {
+                            return Err(syn::Error::new_spanned(
+                                other,
+                                r#"expected a fixture name, `path = "..."`, or `glob = "..."`"#,
+                            ));
+                        }
+                    }
+                }
 
-                migrations = match lit {
-                    // migrations = false
-                    Lit::Bool(b) if !b.value => MigrationsOpt::Disabled,
-                    // migrations = true
-                    Lit::Bool(b) => {
+                if !names.is_empty() {
+                    fixtures = Fixtures::Names(names);
+                }
+            }
+            Meta::NameValue(MetaNameValue { value, .. }) if path.is_ident("migrations") => {
+                // migrations = false
+                if let Expr::Lit(ExprLit { lit: Lit::Bool(b), .. }) = &value {
+                    if b.value {
                         return Err(syn::Error::new_spanned(
                             b,
                             "`migrations = true` is redundant",
                         ));
                     }
-                    // migrations = "path"
-                    Lit::Str(s) => MigrationsOpt::ExplicitPath(s),
-                    lit => return Err(syn::Error::new_spanned(lit, "expected string or `false`")),
+
+                    match migrations {
+                        MigrationsOpt::InferredPath => {}
+                        MigrationsOpt::Disabled => {
+                            return Err(syn::Error::new_spanned(
+                                path,
+                                "duplicate `migrations = false` arg",
+                            ));
+                        }
+                        MigrationsOpt::Explicit(_) => {
+                            return Err(syn::Error::new_spanned(
+                                path,
+                                "cannot combine `migrations = false` with other migration sources",
+                            ));
+                        }
+                    }
+
+                    migrations = MigrationsOpt::Disabled;
+                    continue;
+                }
+
+                // migrations = "path" | migrations = ["path", ...]
+                let dirs = match value {
+                    Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => vec![s],
+                    Expr::Array(array) => array
+                        .elems
+                        .into_iter()
+                        .map(|elem| match elem {
+                            Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Ok(s),
+                            other => Err(syn::Error::new_spanned(
+                                other,
+                                "expected a string literal path",
+                            )),
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "expected string, list of strings, or `false`",
+                        ));
+                    }
                 };
+
+                push_migrator_sources(
+                    &path,
+                    &mut migrations,
+                    dirs.into_iter().map(MigratorSource::Dir),
+                )?;
             }
             // migrator = "path"
             Meta::NameValue(MetaNameValue { value, .. }) if path.is_ident("migrator") => {
-                if !matches!(migrations, MigrationsOpt::InferredPath) {
-                    return Err(syn::Error::new_spanned(
-                        path,
-                        "cannot have more than one `migrations` or `migrator` arg",
-                    ));
-                }
-
                 let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = value else {
                     return Err(syn::Error::new_spanned(path, "expected string"))
                 };
 
-                migrations = MigrationsOpt::ExplicitMigrator(lit.parse()?);
+                push_migrator_sources(
+                    &path,
+                    &mut migrations,
+                    std::iter::once(MigratorSource::Migrator(lit.parse()?)),
+                )?;
             }
             arg => {
                 return Err(syn::Error::new_spanned(
@@ -210,6 +758,7 @@ fn parse_args(args: AttributeArgs) -> Result<Args, syn::Error> {
 
     Ok(Args {
         fixtures,
+        fixture_scheme,
         migrations,
     })
 }